@@ -0,0 +1,697 @@
+//! Hash-consed quadtree engine (HashLife) for simulating Conway's Game of
+//! Life on an effectively infinite plane.
+//!
+//! [`World`](crate::World) models a fixed toroidal grid and evaluates every
+//! live cell on every tick. [`HashWorld`] instead represents the universe as
+//! an immutable quadtree: each node covers a `2^level x 2^level` region and
+//! is either a leaf holding a `2x2` block of cells, or a branch holding four
+//! half-size children. Structurally identical subtrees are canonicalized
+//! through a shared table so they are stored only once, and each node
+//! memoizes the result of advancing its region forward in time, so repeated
+//! substructures (gliders, guns, still lifes) are only ever simulated once
+//! no matter how often they recur across the plane or across generations.
+//!
+//! Because the plane has no edges, `HashWorld` has different semantics from
+//! `World`'s toroidal wrap-around: patterns are free to grow without bound,
+//! and the tree is expanded with an empty border as needed so nothing runs
+//! off the edge of the modelled region.
+
+use crate::Position;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+
+// A leaf node is the smallest unit of the tree: a 2x2 block of cells.
+const LEAF_LEVEL: u8 = 1;
+
+#[derive(Debug)]
+enum Kind {
+    Leaf {
+        nw: bool,
+        ne: bool,
+        sw: bool,
+        se: bool,
+    },
+    Branch {
+        nw: Node,
+        ne: Node,
+        sw: Node,
+        se: Node,
+    },
+}
+
+#[derive(Debug)]
+struct NodeData {
+    level: u8,
+    population: u64,
+    kind: Kind,
+    // Memoized result of advancing this node's region forward by
+    // `2^(level - 2)` generations, populated lazily on first use.
+    result: RefCell<Option<Node>>,
+}
+
+// Canonicalized quadtree node. Cheap to clone: it's a reference-counted
+// handle, and hash-consing guarantees two nodes covering the same
+// structure are the same allocation.
+#[derive(Debug, Clone)]
+struct Node(Rc<NodeData>);
+
+impl Node {
+    fn level(&self) -> u8 {
+        self.0.level
+    }
+
+    fn population(&self) -> u64 {
+        self.0.population
+    }
+
+    // Identity used as a hash-consing key: canonical nodes are equal iff
+    // they are the same allocation.
+    fn identity(&self) -> usize {
+        Rc::as_ptr(&self.0) as usize
+    }
+
+    fn children(&self) -> (Node, Node, Node, Node) {
+        match &self.0.kind {
+            Kind::Branch { nw, ne, sw, se } => (nw.clone(), ne.clone(), sw.clone(), se.clone()),
+            Kind::Leaf { .. } => panic!("leaf node has no children"),
+        }
+    }
+
+    fn leaf_bools(&self) -> (bool, bool, bool, bool) {
+        match &self.0.kind {
+            Kind::Leaf { nw, ne, sw, se } => (*nw, *ne, *sw, *se),
+            Kind::Branch { .. } => panic!("branch node has no leaf bools"),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Hash)]
+enum NodeKey {
+    Leaf(bool, bool, bool, bool),
+    Branch(usize, usize, usize, usize),
+}
+
+// Canonicalization table, plus a per-level cache of canonical empty nodes.
+struct NodeTable {
+    canon: HashMap<NodeKey, Node>,
+    empties: Vec<Node>,
+}
+
+impl NodeTable {
+    fn new() -> NodeTable {
+        let empty_leaf = Node(Rc::new(NodeData {
+            level: LEAF_LEVEL,
+            population: 0,
+            kind: Kind::Leaf {
+                nw: false,
+                ne: false,
+                sw: false,
+                se: false,
+            },
+            result: RefCell::new(None),
+        }));
+
+        let mut canon = HashMap::new();
+        canon.insert(NodeKey::Leaf(false, false, false, false), empty_leaf.clone());
+
+        NodeTable {
+            canon,
+            empties: vec![empty_leaf],
+        }
+    }
+
+    fn leaf(&mut self, nw: bool, ne: bool, sw: bool, se: bool) -> Node {
+        let key = NodeKey::Leaf(nw, ne, sw, se);
+        if let Some(node) = self.canon.get(&key) {
+            return node.clone();
+        }
+
+        let population = [nw, ne, sw, se].iter().filter(|alive| **alive).count() as u64;
+        let node = Node(Rc::new(NodeData {
+            level: LEAF_LEVEL,
+            population,
+            kind: Kind::Leaf { nw, ne, sw, se },
+            result: RefCell::new(None),
+        }));
+        self.canon.insert(key, node.clone());
+        node
+    }
+
+    fn branch(&mut self, nw: Node, ne: Node, sw: Node, se: Node) -> Node {
+        let key = NodeKey::Branch(nw.identity(), ne.identity(), sw.identity(), se.identity());
+        if let Some(node) = self.canon.get(&key) {
+            return node.clone();
+        }
+
+        let level = nw.level() + 1;
+        let population = nw.population() + ne.population() + sw.population() + se.population();
+        let node = Node(Rc::new(NodeData {
+            level,
+            population,
+            kind: Kind::Branch { nw, ne, sw, se },
+            result: RefCell::new(None),
+        }));
+        self.canon.insert(key, node.clone());
+        node
+    }
+
+    // Canonical empty node at the given level, built up from smaller
+    // canonical empty nodes as needed.
+    fn empty(&mut self, level: u8) -> Node {
+        while (self.empties.len() as u8) <= level - LEAF_LEVEL {
+            let smaller = self.empties.last().unwrap().clone();
+            let bigger = self.branch(smaller.clone(), smaller.clone(), smaller.clone(), smaller);
+            self.empties.push(bigger);
+        }
+
+        self.empties[(level - LEAF_LEVEL) as usize].clone()
+    }
+}
+
+#[wasm_bindgen]
+// Infinite-plane Game of Life engine backed by a hash-consed quadtree.
+pub struct HashWorld {
+    table: NodeTable,
+    root: Node,
+    // World-space coordinates of the root node's north-west corner.
+    origin: Position,
+}
+
+#[wasm_bindgen]
+impl HashWorld {
+    // Build the smallest quadtree that contains every given live cell.
+    pub fn from_positions(positions: Vec<Position>) -> HashWorld {
+        let mut table = NodeTable::new();
+        let (root, origin) = Self::build_tree(&mut table, positions);
+
+        HashWorld { table, root, origin }
+    }
+
+    // Advance the world by exactly `generations`. Each iteration pads the
+    // tree with an empty border and takes the largest memoized jump that
+    // doesn't overshoot the generations still owed; once the tree's
+    // natural jump is bigger than what's left, the remainder is advanced
+    // one generation at a time.
+    pub fn step(&mut self, generations: u64) {
+        let mut remaining = generations;
+        while remaining > 0 {
+            while !Self::is_double_padded(&self.root) {
+                self.expand();
+            }
+
+            let jump = Self::natural_max(self.root.level());
+            if jump > 0 && jump <= remaining {
+                self.advance();
+                remaining -= jump;
+            } else {
+                self.step_one();
+                remaining -= 1;
+            }
+        }
+    }
+
+    // Expose current live cells to JS.
+    pub fn alive_positions(&self) -> Vec<Position> {
+        let mut positions = Vec::new();
+        Self::collect(&self.root, self.origin, &mut positions);
+        positions
+    }
+}
+
+impl HashWorld {
+    // Build the smallest quadtree containing every given live cell using
+    // `table`, so structurally identical subtrees already canonicalized
+    // there (and any memoized `result` they carry) are reused rather
+    // than rebuilt from scratch. Returns the new root and its origin.
+    fn build_tree(table: &mut NodeTable, positions: Vec<Position>) -> (Node, Position) {
+        if positions.is_empty() {
+            return (table.empty(LEAF_LEVEL), Position::new(0, 0));
+        }
+
+        let min_x = positions.iter().map(|p| p.x).min().unwrap();
+        let max_x = positions.iter().map(|p| p.x).max().unwrap();
+        let min_y = positions.iter().map(|p| p.y).min().unwrap();
+        let max_y = positions.iter().map(|p| p.y).max().unwrap();
+
+        // Smallest power-of-two square (at least 2x2) that contains every
+        // live cell, anchored at its north-west corner.
+        let span = (max_x - min_x + 1).max(max_y - min_y + 1).max(2);
+        let mut level = LEAF_LEVEL;
+        while (1i64 << level) < span {
+            level += 1;
+        }
+
+        let alive: HashSet<Position> = positions.into_iter().collect();
+        let origin = Position::new(min_x, min_y);
+        let root = Self::build(table, level, origin, &alive);
+
+        (root, origin)
+    }
+
+    // Recursively build a node of the given level covering `origin` from
+    // the live cells in `alive`.
+    fn build(table: &mut NodeTable, level: u8, origin: Position, alive: &HashSet<Position>) -> Node {
+        if level == LEAF_LEVEL {
+            let nw = alive.contains(&Position::new(origin.x, origin.y));
+            let ne = alive.contains(&Position::new(origin.x + 1, origin.y));
+            let sw = alive.contains(&Position::new(origin.x, origin.y + 1));
+            let se = alive.contains(&Position::new(origin.x + 1, origin.y + 1));
+            return table.leaf(nw, ne, sw, se);
+        }
+
+        let half = 1i64 << (level - 1);
+        let nw = Self::build(table, level - 1, Position::new(origin.x, origin.y), alive);
+        let ne = Self::build(table, level - 1, Position::new(origin.x + half, origin.y), alive);
+        let sw = Self::build(table, level - 1, Position::new(origin.x, origin.y + half), alive);
+        let se = Self::build(
+            table,
+            level - 1,
+            Position::new(origin.x + half, origin.y + half),
+            alive,
+        );
+
+        table.branch(nw, ne, sw, se)
+    }
+
+    fn collect(node: &Node, origin: Position, out: &mut Vec<Position>) {
+        if node.population() == 0 {
+            return;
+        }
+
+        match &node.0.kind {
+            Kind::Leaf { nw, ne, sw, se } => {
+                if *nw {
+                    out.push(Position::new(origin.x, origin.y));
+                }
+                if *ne {
+                    out.push(Position::new(origin.x + 1, origin.y));
+                }
+                if *sw {
+                    out.push(Position::new(origin.x, origin.y + 1));
+                }
+                if *se {
+                    out.push(Position::new(origin.x + 1, origin.y + 1));
+                }
+            }
+            Kind::Branch { nw, ne, sw, se } => {
+                let half = 1i64 << (node.level() - 1);
+                Self::collect(nw, origin, out);
+                Self::collect(ne, Position::new(origin.x + half, origin.y), out);
+                Self::collect(sw, Position::new(origin.x, origin.y + half), out);
+                Self::collect(se, Position::new(origin.x + half, origin.y + half), out);
+            }
+        }
+    }
+
+    // Population of each of `node`'s four grandchildren (nw, ne, sw, se),
+    // treating a leaf's own four cells as if they were its grandchildren.
+    fn grandchild_populations(node: &Node) -> (u64, u64, u64, u64) {
+        match &node.0.kind {
+            Kind::Leaf { nw, ne, sw, se } => (*nw as u64, *ne as u64, *sw as u64, *se as u64),
+            Kind::Branch { nw, ne, sw, se } => {
+                (nw.population(), ne.population(), sw.population(), se.population())
+            }
+        }
+    }
+
+    // True if `node`'s live cells are confined to the single grandchild
+    // nearest the center in each of its four children, i.e. there is a
+    // full empty-border level around the pattern. A leaf has no border
+    // at all, so it's never considered padded.
+    fn is_padded(node: &Node) -> bool {
+        match &node.0.kind {
+            Kind::Leaf { .. } => false,
+            Kind::Branch { nw, ne, sw, se } => {
+                let (nw_nw, nw_ne, nw_sw, _) = Self::grandchild_populations(nw);
+                let (ne_nw, ne_ne, _, ne_se) = Self::grandchild_populations(ne);
+                let (sw_nw, _, sw_sw, sw_se) = Self::grandchild_populations(sw);
+                let (_, se_ne, se_sw, se_se) = Self::grandchild_populations(se);
+
+                nw_nw == 0
+                    && nw_ne == 0
+                    && nw_sw == 0
+                    && ne_nw == 0
+                    && ne_ne == 0
+                    && ne_se == 0
+                    && sw_nw == 0
+                    && sw_sw == 0
+                    && sw_se == 0
+                    && se_ne == 0
+                    && se_sw == 0
+                    && se_se == 0
+            }
+        }
+    }
+
+    // True if `node` is padded *and* the live cells are confined one
+    // ring further still, to the central sub-quadrant rather than
+    // merely the central half. `result`'s nine-overlapping-square
+    // construction recurses into synthetic squares built from `node`'s
+    // own children (e.g. its literal nw child) as if they were
+    // self-contained universes; a single empty-border ring only makes
+    // that recursion sound for `node` itself; a pattern sitting flush
+    // against one of those inner children's own edges would let live
+    // cells drift in from that child's true neighbor during the
+    // quarter-advance, with no border left to absorb it. Advancing by
+    // `node`'s full natural-max jump is only safe once this holds.
+    fn is_double_padded(node: &Node) -> bool {
+        if !Self::is_padded(node) {
+            return false;
+        }
+
+        let Kind::Branch { nw, ne, sw, se } = &node.0.kind else {
+            return false;
+        };
+
+        // At the smallest branch level, `node`'s children are leaves:
+        // the single permitted grandchild per child is already an
+        // atomic cell, so there's no further ring to check.
+        if nw.level() < 2 {
+            return true;
+        }
+
+        let (nw_nw, nw_ne, nw_sw, _) = Self::grandchild_populations(&nw.children().3);
+        let (ne_nw, ne_ne, _, ne_se) = Self::grandchild_populations(&ne.children().2);
+        let (sw_nw, _, sw_sw, sw_se) = Self::grandchild_populations(&sw.children().1);
+        let (_, se_ne, se_sw, se_se) = Self::grandchild_populations(&se.children().0);
+
+        nw_nw == 0
+            && nw_ne == 0
+            && nw_sw == 0
+            && ne_nw == 0
+            && ne_ne == 0
+            && ne_se == 0
+            && sw_nw == 0
+            && sw_sw == 0
+            && sw_se == 0
+            && se_ne == 0
+            && se_sw == 0
+            && se_se == 0
+    }
+
+    // Double the tree's span, keeping the existing content centered
+    // within a fresh empty border so growth or movement can't fall off
+    // the edge of the modelled region.
+    fn expand(&mut self) {
+        let old_level = self.root.level();
+        self.root = Self::expand_node(&mut self.table, &self.root);
+
+        let delta = 1i64 << (old_level - 1);
+        self.origin.x -= delta;
+        self.origin.y -= delta;
+    }
+
+    fn expand_node(table: &mut NodeTable, node: &Node) -> Node {
+        match &node.0.kind {
+            Kind::Leaf { nw, ne, sw, se } => {
+                let new_nw = table.leaf(false, false, false, *nw);
+                let new_ne = table.leaf(false, false, *ne, false);
+                let new_sw = table.leaf(false, *sw, false, false);
+                let new_se = table.leaf(*se, false, false, false);
+                table.branch(new_nw, new_ne, new_sw, new_se)
+            }
+            Kind::Branch { nw, ne, sw, se } => {
+                let empty = table.empty(nw.level());
+                let new_nw = table.branch(empty.clone(), empty.clone(), empty.clone(), nw.clone());
+                let new_ne = table.branch(empty.clone(), empty.clone(), ne.clone(), empty.clone());
+                let new_sw = table.branch(empty.clone(), sw.clone(), empty.clone(), empty.clone());
+                let new_se = table.branch(se.clone(), empty.clone(), empty.clone(), empty.clone());
+                table.branch(new_nw, new_ne, new_sw, new_se)
+            }
+        }
+    }
+
+    // The most generations a node of `level` can safely be advanced by in
+    // one call: `2^(level - 2)`, or `0` below the smallest branch level.
+    fn natural_max(level: u8) -> u64 {
+        if level < 2 {
+            0
+        } else {
+            1u64 << (level - 2)
+        }
+    }
+
+    // Advance the whole tree by its current natural maximum,
+    // `2^(root.level() - 2)` generations, via the memoized `result` of
+    // the (padded) root.
+    fn advance(&mut self) {
+        let node = self.root.clone();
+        let shift = 1i64 << (node.level() - 2);
+
+        self.root = self.result(&node);
+        self.origin.x += shift;
+        self.origin.y += shift;
+    }
+
+    // Advance the whole tree by exactly one generation, the same way
+    // `World` does: count live neighbors of every cell adjacent to a
+    // live cell and apply the standard birth/survival rule, then rebuild
+    // the smallest tree containing the result. Used for the remainder of
+    // a `step` too small for another full `advance` jump.
+    fn step_one(&mut self) {
+        let mut alive = Vec::new();
+        Self::collect(&self.root, self.origin, &mut alive);
+        let alive: HashSet<Position> = alive.into_iter().collect();
+
+        let mut neighbor_counts: HashMap<Position, u8> = HashMap::new();
+        for pos in &alive {
+            for dy in -1..=1i64 {
+                for dx in -1..=1i64 {
+                    if (dx, dy) == (0, 0) {
+                        continue;
+                    }
+                    *neighbor_counts
+                        .entry(Position::new(pos.x + dx, pos.y + dy))
+                        .or_insert(0) += 1;
+                }
+            }
+        }
+
+        let next: Vec<Position> = neighbor_counts
+            .into_iter()
+            .filter(|&(pos, count)| count == 3 || (count == 2 && alive.contains(&pos)))
+            .map(|(pos, _)| pos)
+            .collect();
+
+        let (root, origin) = Self::build_tree(&mut self.table, next);
+        self.root = root;
+        self.origin = origin;
+    }
+
+    // Compute (and cache) the result of advancing `node`'s region forward
+    // by `2^(level - 2)` generations, returning the evolved center square
+    // one level smaller than `node`.
+    fn result(&mut self, node: &Node) -> Node {
+        if let Some(cached) = node.0.result.borrow().clone() {
+            return cached;
+        }
+
+        let computed = if node.level() == 2 {
+            self.base_case(node)
+        } else {
+            self.general_case(node)
+        };
+
+        *node.0.result.borrow_mut() = Some(computed.clone());
+        computed
+    }
+
+    // Base case: a 4x4 block (two levels of leaves). Simulate exactly one
+    // generation directly and return the evolved center 2x2 as a leaf.
+    fn base_case(&mut self, node: &Node) -> Node {
+        let (nw, ne, sw, se) = node.children();
+        let (nw_nw, nw_ne, nw_sw, nw_se) = nw.leaf_bools();
+        let (ne_nw, ne_ne, ne_sw, ne_se) = ne.leaf_bools();
+        let (sw_nw, sw_ne, sw_sw, sw_se) = sw.leaf_bools();
+        let (se_nw, se_ne, se_sw, se_se) = se.leaf_bools();
+
+        let cells = [
+            [nw_nw, nw_ne, ne_nw, ne_ne],
+            [nw_sw, nw_se, ne_sw, ne_se],
+            [sw_nw, sw_ne, se_nw, se_ne],
+            [sw_sw, sw_se, se_sw, se_se],
+        ];
+
+        let alive_at = |x: i64, y: i64| -> bool {
+            if !(0..4).contains(&x) || !(0..4).contains(&y) {
+                false
+            } else {
+                cells[y as usize][x as usize]
+            }
+        };
+
+        let next = |x: i64, y: i64| -> bool {
+            let mut live_neighbors = 0;
+            for dy in -1..=1 {
+                for dx in -1..=1 {
+                    if (dx, dy) != (0, 0) && alive_at(x + dx, y + dy) {
+                        live_neighbors += 1;
+                    }
+                }
+            }
+
+            if alive_at(x, y) {
+                live_neighbors == 2 || live_neighbors == 3
+            } else {
+                live_neighbors == 3
+            }
+        };
+
+        self.table.leaf(next(1, 1), next(2, 1), next(1, 2), next(2, 2))
+    }
+
+    // General case (level >= 3): assemble nine overlapping sub-squares
+    // from `node`'s children and grandchildren, advance each by an eighth
+    // of `node`'s span (`2^(level - 3)` generations), combine those into
+    // four squares, and advance those by the same amount again to reach
+    // the full quarter-span (`2^(level - 2)`) advance this node's result
+    // represents.
+    fn general_case(&mut self, node: &Node) -> Node {
+        let (a, b, c, d) = node.children();
+        let (_a_nw, a_ne, a_sw, a_se) = a.children();
+        let (b_nw, _b_ne, b_sw, b_se) = b.children();
+        let (c_nw, c_ne, _c_sw, c_se) = c.children();
+        let (d_nw, d_ne, d_sw, _d_se) = d.children();
+
+        let n00 = a.clone();
+        let n01 = self.table.branch(a_ne, b_nw, a_se.clone(), b_sw.clone());
+        let n02 = b.clone();
+        let n10 = self.table.branch(a_sw, a_se.clone(), c_nw, c_ne.clone());
+        let n11 = self.table.branch(a_se, b_sw.clone(), c_ne.clone(), d_nw.clone());
+        let n12 = self.table.branch(b_sw, b_se, d_nw.clone(), d_ne);
+        let n20 = c.clone();
+        let n21 = self.table.branch(c_ne, d_nw, c_se, d_sw);
+        let n22 = d.clone();
+
+        let r00 = self.result(&n00);
+        let r01 = self.result(&n01);
+        let r02 = self.result(&n02);
+        let r10 = self.result(&n10);
+        let r11 = self.result(&n11);
+        let r12 = self.result(&n12);
+        let r20 = self.result(&n20);
+        let r21 = self.result(&n21);
+        let r22 = self.result(&n22);
+
+        let new_nw = self.table.branch(r00, r01.clone(), r10.clone(), r11.clone());
+        let new_ne = self.table.branch(r01, r02, r11.clone(), r12.clone());
+        let new_sw = self.table.branch(r10, r11.clone(), r20, r21.clone());
+        let new_se = self.table.branch(r11, r12, r21, r22);
+
+        let final_nw = self.result(&new_nw);
+        let final_ne = self.result(&new_ne);
+        let final_sw = self.result(&new_sw);
+        let final_se = self.result(&new_se);
+
+        self.table.branch(final_nw, final_ne, final_sw, final_se)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    // An empty world has no live cells and advances to itself.
+    fn test_empty_world() {
+        let mut world = HashWorld::from_positions(vec![]);
+        world.step(10);
+
+        assert!(world.alive_positions().is_empty());
+    }
+
+    #[test]
+    // A 2x2 block is a still life: it must be unchanged after stepping.
+    fn test_block_is_stable() {
+        let block = vec![
+            Position::new(0, 0),
+            Position::new(1, 0),
+            Position::new(0, 1),
+            Position::new(1, 1),
+        ];
+        let mut world = HashWorld::from_positions(block.clone());
+        world.step(5);
+
+        let mut alive = world.alive_positions();
+        alive.sort_by_key(|p| (p.x, p.y));
+        let mut expected = block;
+        expected.sort_by_key(|p| (p.x, p.y));
+
+        assert_eq!(alive, expected);
+    }
+
+    #[test]
+    // A glider drifts by (1, 1) every 4 generations.
+    fn test_glider_drifts_diagonally() {
+        let glider = vec![
+            Position::new(1, 0),
+            Position::new(2, 1),
+            Position::new(0, 2),
+            Position::new(1, 2),
+            Position::new(2, 2),
+        ];
+        let mut world = HashWorld::from_positions(glider.clone());
+        world.step(4);
+
+        let alive: HashSet<Position> = world.alive_positions().into_iter().collect();
+        let expected: HashSet<Position> = glider
+            .into_iter()
+            .map(|p| Position::new(p.x + 1, p.y + 1))
+            .collect();
+
+        assert_eq!(alive, expected);
+    }
+
+    #[test]
+    // A horizontal blinker is a period-2 oscillator: after an odd number
+    // of generations it must be in its vertical phase, exercising small
+    // step counts that don't naturally land on a full quarter-advance.
+    fn test_blinker_oscillates_with_exact_phase() {
+        let blinker = vec![Position::new(0, 0), Position::new(1, 0), Position::new(2, 0)];
+        let vertical: HashSet<Position> = [Position::new(1, -1), Position::new(1, 0), Position::new(1, 1)]
+            .into_iter()
+            .collect();
+
+        let mut after_one = HashWorld::from_positions(blinker.clone());
+        after_one.step(1);
+        let alive: HashSet<Position> = after_one.alive_positions().into_iter().collect();
+        assert_eq!(alive, vertical);
+
+        let mut after_three = HashWorld::from_positions(blinker);
+        after_three.step(3);
+        let alive: HashSet<Position> = after_three.alive_positions().into_iter().collect();
+        assert_eq!(alive, vertical);
+    }
+
+    #[test]
+    // A single `step` call by a count spanning multiple memoized jumps
+    // must land on the same generation as advancing one at a time. This
+    // exercises jump sizes (6, 8, and 20+) that don't naturally align
+    // with the tree's padding, which previously let live cells drift
+    // out of the region a jump kept and get silently dropped.
+    fn test_glider_jump_matches_repeated_single_steps() {
+        let glider = vec![
+            Position::new(1, 0),
+            Position::new(2, 1),
+            Position::new(0, 2),
+            Position::new(1, 2),
+            Position::new(2, 2),
+        ];
+
+        for generations in [6, 8, 20, 37] {
+            let mut single_stepped = HashWorld::from_positions(glider.clone());
+            for _ in 0..generations {
+                single_stepped.step(1);
+            }
+            let expected: HashSet<Position> = single_stepped.alive_positions().into_iter().collect();
+
+            let mut jumped = HashWorld::from_positions(glider.clone());
+            jumped.step(generations);
+            let alive: HashSet<Position> = jumped.alive_positions().into_iter().collect();
+
+            assert_eq!(alive, expected, "mismatch after step({generations})");
+        }
+    }
+}
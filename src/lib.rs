@@ -25,12 +25,22 @@
 //!   ([`glider_pattern`], [`pulsar_pattern`]).
 //! - Advance the simulation in-place using [`World::tick`].
 //! - Retrieve current live cells with [`World::alive_positions`].
+//! - Edit a world interactively with [`World::set_cell`], [`World::toggle`],
+//!   [`World::clear`], and [`World::randomize`].
+//! - Import or export a pattern in Golly's RLE format with [`World::from_rle`]
+//!   and [`World::to_rle`].
+//! - Render the grid as text with [`World::render`] or [`World::render_with`].
+//! - Detect still lifes and oscillators with [`World::run_until_stable`].
 //!
 //! ## Performance note
-//! Alive cells are stored in a `Vec<Position>`, and membership checks use
-//! `Vec::contains`, which is `O(n)`. For large worlds or dense populations,
-//! consider switching to a `HashSet`-based representation if performance becomes
-//! an issue.
+//! By default, alive cells are stored in a `Vec<Position>`, and membership
+//! checks use `Vec::contains`, which is `O(n)`. [`World::dense`] instead
+//! backs the world with a bit-packed grid, giving `O(1)` membership checks
+//! and `O(width * height)` ticks independent of population density, at the
+//! cost of allocating the full grid up front. For very large, sparse, or
+//! highly repetitive patterns, see [`HashWorld`] instead: it models an
+//! unbounded plane as a hash-consed quadtree and memoizes generational
+//! advances per node.
 //!
 //! ## Example
 //! ```no_run
@@ -44,9 +54,14 @@
 //! [`wasm_bindgen`]: https://docs.rs/wasm-bindgen
 extern crate wasm_bindgen;
 
-use std::collections::HashSet;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
 use wasm_bindgen::prelude::*;
 
+mod hash_life;
+pub use hash_life::HashWorld;
+
 #[wasm_bindgen]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 // Simple coordinate for an individual cell.
@@ -64,29 +79,446 @@ impl Position {
     }
 }
 
+// Default Life-like rule: a live cell survives on 2 or 3 neighbors, and a
+// dead cell is born on exactly 3 (standard Conway's Life, "B3/S23").
+const DEFAULT_BIRTH_MASK: u16 = 1 << 3;
+const DEFAULT_SURVIVAL_MASK: u16 = (1 << 2) | (1 << 3);
+
 #[wasm_bindgen]
-// Toroidal world state and its live cell positions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+// Error returned when parsing a malformed rule string or pattern.
+pub struct ParseError(String);
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// Parse a Life-like rule in `B.../S...` notation into birth and survival
+// bitmasks, where bit `n` set means "n live neighbors triggers the action".
+fn parse_rule(rule: &str) -> Result<(u16, u16), ParseError> {
+    let mut parts = rule.split('/');
+
+    let births_part = parts
+        .next()
+        .ok_or_else(|| ParseError(format!("empty rule string {:?}", rule)))?;
+    let survival_part = parts
+        .next()
+        .ok_or_else(|| ParseError(format!("rule {:?} is missing a '/S...' part", rule)))?;
+
+    if parts.next().is_some() {
+        return Err(ParseError(format!("rule {:?} has too many '/' separated parts", rule)));
+    }
+
+    let birth_mask = parse_counts(births_part, 'B')?;
+    let survival_mask = parse_counts(survival_part, 'S')?;
+
+    Ok((birth_mask, survival_mask))
+}
+
+// Parse a single `B` or `S` segment (e.g. `B36`, `S`) into a bitmask.
+fn parse_counts(segment: &str, tag: char) -> Result<u16, ParseError> {
+    let digits = segment
+        .strip_prefix(tag)
+        .ok_or_else(|| ParseError(format!("expected {:?} to start with '{}'", segment, tag)))?;
+
+    let mut mask = 0u16;
+    for ch in digits.chars() {
+        let count = ch
+            .to_digit(10)
+            .ok_or_else(|| ParseError(format!("invalid neighbor count {:?} in rule", ch)))?;
+
+        if count > 8 {
+            return Err(ParseError(format!("neighbor count {} is out of range 0..=8", count)));
+        }
+
+        mask |= 1 << count;
+    }
+
+    Ok(mask)
+}
+
+// Parse a Golly RLE pattern: an optional header line giving the pattern's
+// rule (`x = W, y = H, rule = B.../S...`), followed by run-length-encoded
+// cell data terminated by `!`. Lines starting with `#` are comments and are
+// skipped. Returns the live cell positions, 1-based and anchored at the
+// pattern's top-left corner, plus the rule string from the header, if any.
+fn parse_rle(rle: &str) -> Result<(Vec<Position>, Option<String>), ParseError> {
+    let mut rule = None;
+    let mut body = String::new();
+
+    for line in rle.lines() {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if line.starts_with('x') {
+            if let Some(rule_part) = line.split("rule").nth(1) {
+                let rule_str = rule_part.trim_start_matches([' ', '=']).trim();
+                rule = Some(rule_str.to_string());
+            }
+            continue;
+        }
+
+        body.push_str(line);
+    }
+
+    let mut positions = Vec::new();
+    let mut run_length = String::new();
+    let mut x = 1i64;
+    let mut y = 1i64;
+
+    for ch in body.chars() {
+        match ch {
+            '0'..='9' => run_length.push(ch),
+            'b' | 'o' | '$' => {
+                let count = if run_length.is_empty() {
+                    1
+                } else {
+                    run_length.parse::<i64>().map_err(|_| {
+                        ParseError(format!("invalid run length {:?} in RLE data", run_length))
+                    })?
+                };
+                run_length.clear();
+
+                match ch {
+                    'b' => x += count,
+                    'o' => {
+                        for _ in 0..count {
+                            positions.push(Position::new(x, y));
+                            x += 1;
+                        }
+                    }
+                    '$' => {
+                        y += count;
+                        x = 1;
+                    }
+                    _ => unreachable!(),
+                }
+            }
+            '!' => break,
+            other => {
+                return Err(ParseError(format!(
+                    "unexpected character {:?} in RLE data",
+                    other
+                )))
+            }
+        }
+    }
+
+    Ok((positions, rule))
+}
+
+// Minimal xorshift64 PRNG used by `World::randomize`. Deterministic given a
+// seed; not suitable for anything security-sensitive.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Rng {
+        // A zero seed would stay zero forever under xorshift, so substitute
+        // a fixed non-zero value.
+        Rng(if seed == 0 { 0x9E37_79B9_7F4A_7C15 } else { seed })
+    }
+
+    // Next pseudo-random value in `0.0..1.0`.
+    fn next_f64(&mut self) -> f64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+
+        (self.0 >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+// Backing store for a world's live cells: either a sparse list (cheap for
+// low-density worlds, but `O(n)` membership checks) or a dense, bit-packed
+// grid (an `O(1)` membership check and `O(width * height)` ticks no matter
+// how dense the population gets).
+enum Storage {
+    Sparse(Vec<Position>),
+    Dense(DenseGrid),
+}
+
+// A `width * height` bitset, one bit per cell, indexed by `row * width + col`
+// over 0-based coordinates.
+struct DenseGrid {
+    width: i64,
+    height: i64,
+    bits: Vec<u64>,
+}
+
+impl DenseGrid {
+    fn new(width: i64, height: i64) -> DenseGrid {
+        let cell_count = (width * height) as usize;
+        let word_count = cell_count.div_ceil(64);
+
+        DenseGrid {
+            width,
+            height,
+            bits: vec![0u64; word_count],
+        }
+    }
+
+    // `position` must already be wrapped into `1..=width, 1..=height`.
+    fn index(&self, position: Position) -> usize {
+        let x = (position.x - 1) as usize;
+        let y = (position.y - 1) as usize;
+
+        y * self.width as usize + x
+    }
+
+    fn get(&self, position: Position) -> bool {
+        let index = self.index(position);
+
+        (self.bits[index / 64] >> (index % 64)) & 1 == 1
+    }
+
+    fn set(&mut self, position: Position, alive: bool) {
+        let index = self.index(position);
+        let (word, bit) = (index / 64, index % 64);
+
+        if alive {
+            self.bits[word] |= 1 << bit;
+        } else {
+            self.bits[word] &= !(1 << bit);
+        }
+    }
+
+    // Scan the bitset and map set bits back to their 1-based positions.
+    fn alive_positions(&self) -> Vec<Position> {
+        let mut positions = Vec::new();
+
+        for y in 0..self.height {
+            for x in 0..self.width {
+                if self.get(Position::new(x + 1, y + 1)) {
+                    positions.push(Position::new(x + 1, y + 1));
+                }
+            }
+        }
+
+        positions
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+// Outcome of `World::run_until_stable`.
+pub struct StabilizationResult {
+    // Number of generations actually simulated.
+    pub steps: usize,
+    // Cycle length once stabilized: `1` for a still life, `>1` for an
+    // oscillator (or a spaceship, under the toroidal wrap), or `0` if no
+    // cycle was found within `max_steps`.
+    pub period: usize,
+}
+
+#[wasm_bindgen]
+// Toroidal world state, its live cell positions, and its Life-like rule.
 pub struct World {
     width: i64,
     height: i64,
-    alive: Vec<Position>,
+    cells: Storage,
+    birth_mask: u16,
+    survival_mask: u16,
 }
 
 #[wasm_bindgen]
 impl World {
     #[wasm_bindgen(constructor)]
-    // Create a world with given dimensions and initial live cells.
+    // Create a world with given dimensions and initial live cells, using
+    // the standard Conway's Life rule (`B3/S23`).
     pub fn new(width: u32, height: u32, alive: Vec<Position>) -> World {
         World {
             width: width.into(),
             height: height.into(),
-            alive: alive,
+            cells: Storage::Sparse(alive),
+            birth_mask: DEFAULT_BIRTH_MASK,
+            survival_mask: DEFAULT_SURVIVAL_MASK,
+        }
+    }
+
+    // Create a world backed by a dense, bit-packed grid instead of a
+    // sparse `Vec<Position>`. Ticks become `O(width * height)`, independent
+    // of population density, at the cost of `O(width * height)` memory
+    // regardless of how few cells are alive.
+    pub fn dense(width: u32, height: u32, alive: Vec<Position>) -> World {
+        let (width, height) = (width as i64, height as i64);
+        let mut grid = DenseGrid::new(width, height);
+
+        for position in alive {
+            let x = (position.x - 1).rem_euclid(width) + 1;
+            let y = (position.y - 1).rem_euclid(height) + 1;
+            grid.set(Position::new(x, y), true);
+        }
+
+        World {
+            width,
+            height,
+            cells: Storage::Dense(grid),
+            birth_mask: DEFAULT_BIRTH_MASK,
+            survival_mask: DEFAULT_SURVIVAL_MASK,
+        }
+    }
+
+    // Create a world with an arbitrary Life-like rule given in standard
+    // `B3/S23` notation, e.g. `B36/S23` for HighLife or `B2/S` for Seeds.
+    pub fn with_rule(width: u32, height: u32, alive: Vec<Position>, rule: &str) -> Result<World, ParseError> {
+        let (birth_mask, survival_mask) = parse_rule(rule)?;
+
+        Ok(World {
+            width: width.into(),
+            height: height.into(),
+            cells: Storage::Sparse(alive),
+            birth_mask,
+            survival_mask,
+        })
+    }
+
+    // Create a world from a Golly RLE pattern string. The pattern is
+    // anchored at `(1, 1)` in a `width` by `height` toroidal world; if the
+    // RLE header specifies a rule, it overrides the default `B3/S23`.
+    pub fn from_rle(width: u32, height: u32, rle: &str) -> Result<World, ParseError> {
+        let (alive, rule) = parse_rle(rle)?;
+
+        match rule {
+            Some(rule) => World::with_rule(width, height, alive, &rule),
+            None => Ok(World::new(width, height, alive)),
         }
     }
 
+    // Encode this world's live cells as a Golly RLE pattern, trimmed to the
+    // bounding box of the live cells. Returns `"x = 0, y = 0\n!\n"` for an
+    // empty world.
+    pub fn to_rle(&self) -> String {
+        let alive = self.alive_positions();
+
+        if alive.is_empty() {
+            return "x = 0, y = 0\n!\n".to_string();
+        }
+
+        let min_x = alive.iter().map(|pos| pos.x).min().unwrap();
+        let max_x = alive.iter().map(|pos| pos.x).max().unwrap();
+        let min_y = alive.iter().map(|pos| pos.y).min().unwrap();
+        let max_y = alive.iter().map(|pos| pos.y).max().unwrap();
+
+        let alive_set: HashSet<Position> = alive.into_iter().collect();
+        let mut body = String::new();
+
+        for y in min_y..=max_y {
+            let mut runs: Vec<(i64, char)> = Vec::new();
+            let mut x = min_x;
+
+            while x <= max_x {
+                let is_alive = alive_set.contains(&Position::new(x, y));
+                let run_start = x;
+
+                while x <= max_x && alive_set.contains(&Position::new(x, y)) == is_alive {
+                    x += 1;
+                }
+
+                runs.push((x - run_start, if is_alive { 'o' } else { 'b' }));
+            }
+
+            if matches!(runs.last(), Some((_, 'b'))) {
+                runs.pop();
+            }
+
+            for (count, tag) in runs {
+                if count > 1 {
+                    body.push_str(&count.to_string());
+                }
+                body.push(tag);
+            }
+
+            if y < max_y {
+                body.push('$');
+            }
+        }
+
+        body.push('!');
+
+        format!("x = {}, y = {}\n{}\n", max_x - min_x + 1, max_y - min_y + 1, body)
+    }
+
+    // Render the world as a grid of Unicode glyphs, one row per line,
+    // using `◼` for live cells and `◻` for dead ones.
+    pub fn render(&self) -> String {
+        self.render_with('◼', '◻')
+    }
+
+    // Render the world as a grid of glyphs, one row per line, using `alive`
+    // and `dead` as the live/dead characters.
+    pub fn render_with(&self, alive: char, dead: char) -> String {
+        let mut rendered = String::new();
+
+        for y in 1..=self.height {
+            for x in 1..=self.width {
+                rendered.push(if self.is_alive(Position::new(x, y)) {
+                    alive
+                } else {
+                    dead
+                });
+            }
+
+            if y < self.height {
+                rendered.push('\n');
+            }
+        }
+
+        rendered
+    }
+
+    // Advance the world one generation at a time, looking for a repeated
+    // state, for up to `max_steps` generations. Returns the step at which a
+    // repeat was found and the cycle's period (`1` for a still life), or
+    // `period: 0` if `max_steps` was exhausted without finding one.
+    pub fn run_until_stable(&mut self, max_steps: usize) -> StabilizationResult {
+        let mut seen = HashMap::new();
+        seen.insert(self.state_hash(), 0);
+
+        for step in 1..=max_steps {
+            self.tick();
+            let hash = self.state_hash();
+
+            if let Some(&first_seen) = seen.get(&hash) {
+                return StabilizationResult {
+                    steps: step,
+                    period: step - first_seen,
+                };
+            }
+
+            seen.insert(hash, step);
+        }
+
+        StabilizationResult {
+            steps: max_steps,
+            period: 0,
+        }
+    }
+
+    // Hash the canonicalized (sorted) set of live cells, so that two
+    // generations with identical live cells hash equal regardless of
+    // enumeration order.
+    fn state_hash(&self) -> u64 {
+        let mut alive = self.alive_positions();
+        alive.sort_by_key(|pos| (pos.x, pos.y));
+
+        let mut hasher = DefaultHasher::new();
+        alive.hash(&mut hasher);
+        hasher.finish()
+    }
+
     // Membership check for a live cell.
     fn is_alive(&self, position: Position) -> bool {
-        self.alive.contains(&position)
+        match &self.cells {
+            Storage::Sparse(alive) => alive.contains(&position),
+            Storage::Dense(grid) => grid.get(self.wrap(position)),
+        }
     }
 
     // Convenience for "not alive".
@@ -128,46 +560,95 @@ impl World {
         results
     }
 
-    // Count live neighbors around a position.
+    // Count live neighbors around a position. The dense grid is naturally
+    // indexed, so it computes neighbor indices directly with toroidal
+    // modulo arithmetic instead of allocating a `Vec<Position>` per cell.
     fn live_neighbors(&self, position: Position) -> usize {
-        self.neighbors(position)
-            .into_iter()
-            .filter(|pos| self.is_alive(*pos))
-            .count()
+        match &self.cells {
+            Storage::Sparse(_) => self
+                .neighbors(position)
+                .into_iter()
+                .filter(|pos| self.is_alive(*pos))
+                .count(),
+            Storage::Dense(grid) => {
+                let mut count = 0;
+
+                for dy in -1..=1 {
+                    for dx in -1..=1 {
+                        if dx == 0 && dy == 0 {
+                            continue;
+                        }
+
+                        let x = (position.x - 1 + dx).rem_euclid(self.width) + 1;
+                        let y = (position.y - 1 + dy).rem_euclid(self.height) + 1;
+
+                        if grid.get(Position::new(x, y)) {
+                            count += 1;
+                        }
+                    }
+                }
+
+                count
+            }
+        }
     }
 
     // Compute survivors among the current live cells.
     fn survivors(&self) -> Vec<Position> {
-        self.alive
-            .iter()
-            .filter_map(|pos| {
-                let count = self.live_neighbors(*pos);
-                if count == 2 || count == 3 {
-                    Some(pos)
-                } else {
-                    None
-                }
-            })
-            .cloned()
-            .collect()
+        match &self.cells {
+            Storage::Sparse(alive) => alive
+                .iter()
+                .filter(|pos| self.survival_mask & (1 << self.live_neighbors(**pos)) != 0)
+                .cloned()
+                .collect(),
+            Storage::Dense(grid) => grid
+                .alive_positions()
+                .into_iter()
+                .filter(|pos| self.survival_mask & (1 << self.live_neighbors(*pos)) != 0)
+                .collect(),
+        }
     }
 
-    // Compute new births among empty neighbor cells.
+    // Compute new births. The sparse path only considers empty cells
+    // neighboring a live one; the dense path scans every cell directly,
+    // since the grid is already being iterated in full regardless.
     fn births(&self) -> Vec<Position> {
-        let mut potential_births: HashSet<Position> = HashSet::new();
+        match &self.cells {
+            Storage::Sparse(alive) => {
+                let mut potential_births: HashSet<Position> = HashSet::new();
 
-        for pos in &self.alive {
-            for neighbor in self.neighbors(*pos) {
-                if self.is_empty(neighbor) {
-                    potential_births.insert(neighbor);
+                for pos in alive {
+                    for neighbor in self.neighbors(*pos) {
+                        if self.is_empty(neighbor) {
+                            potential_births.insert(neighbor);
+                        }
+                    }
                 }
+
+                potential_births
+                    .into_iter()
+                    .filter(|pos| self.birth_mask & (1 << self.live_neighbors(*pos)) != 0)
+                    .collect()
             }
-        }
+            Storage::Dense(grid) => {
+                let mut births = Vec::new();
 
-        potential_births
-            .into_iter()
-            .filter(|pos| self.live_neighbors(*pos) == 3)
-            .collect()
+                for y in 1..=self.height {
+                    for x in 1..=self.width {
+                        let pos = Position::new(x, y);
+                        if grid.get(pos) {
+                            continue;
+                        }
+
+                        if self.birth_mask & (1 << self.live_neighbors(pos)) != 0 {
+                            births.push(pos);
+                        }
+                    }
+                }
+
+                births
+            }
+        }
     }
 
     // Build the next world state without mutating the current one.
@@ -175,23 +656,90 @@ impl World {
         let mut new_alive = self.survivors();
         new_alive.extend(self.births());
 
+        let cells = match &self.cells {
+            Storage::Sparse(_) => Storage::Sparse(new_alive),
+            Storage::Dense(_) => {
+                let mut grid = DenseGrid::new(self.width, self.height);
+                for pos in new_alive {
+                    grid.set(pos, true);
+                }
+                Storage::Dense(grid)
+            }
+        };
+
         World {
             width: self.width,
             height: self.height,
-            alive: new_alive,
+            cells,
+            birth_mask: self.birth_mask,
+            survival_mask: self.survival_mask,
         }
     }
 
     // Expose current live cells to JS.
     pub fn alive_positions(&self) -> Vec<Position> {
-        self.alive.clone()
+        match &self.cells {
+            Storage::Sparse(alive) => alive.clone(),
+            Storage::Dense(grid) => grid.alive_positions(),
+        }
     }
 
     // Advance the world in place by one generation.
     pub fn tick(&mut self) {
         let next = self.next_generation();
 
-        self.alive = next.alive;
+        self.cells = next.cells;
+    }
+
+    // Set a single cell's alive state, wrapping the position toroidally.
+    pub fn set_cell(&mut self, position: Position, alive: bool) {
+        let position = self.wrap(position);
+
+        match &mut self.cells {
+            Storage::Sparse(cells) => {
+                let already_alive = cells.contains(&position);
+                if alive && !already_alive {
+                    cells.push(position);
+                } else if !alive && already_alive {
+                    cells.retain(|pos| *pos != position);
+                }
+            }
+            Storage::Dense(grid) => grid.set(position, alive),
+        }
+    }
+
+    // Flip a single cell between alive and dead.
+    pub fn toggle(&mut self, position: Position) {
+        let alive = self.is_alive(self.wrap(position));
+
+        self.set_cell(position, !alive);
+    }
+
+    // Kill every cell, leaving an empty world.
+    pub fn clear(&mut self) {
+        match &mut self.cells {
+            Storage::Sparse(cells) => cells.clear(),
+            Storage::Dense(grid) => grid.bits.fill(0),
+        }
+    }
+
+    // Replace the world's contents with a random soup: each cell becomes
+    // alive independently with probability `density` (clamped to
+    // `0.0..=1.0`), driven by a PRNG seeded with `seed` so the same seed
+    // always reproduces the same soup.
+    pub fn randomize(&mut self, density: f64, seed: u64) {
+        let density = density.clamp(0.0, 1.0);
+        let mut rng = Rng::new(seed);
+
+        self.clear();
+
+        for y in 1..=self.height {
+            for x in 1..=self.width {
+                if rng.next_f64() < density {
+                    self.set_cell(Position::new(x, y), true);
+                }
+            }
+        }
     }
 }
 
@@ -264,7 +812,10 @@ mod tests {
 
         assert_eq!(world.width, 10);
         assert_eq!(world.height, 10);
-        assert_eq!(world.alive.len(), 1);
+        match &world.cells {
+            Storage::Sparse(alive) => assert_eq!(alive.len(), 1),
+            Storage::Dense(_) => panic!("expected sparse storage"),
+        }
     }
 
     #[test]
@@ -364,4 +915,261 @@ mod tests {
         assert!(next_world.is_alive(Position::new(2, 1)));
         assert!(next_world.is_alive(Position::new(2, 2)));
     }
+
+    #[test]
+    // B3/S23 should parse to the same masks `World::new` defaults to.
+    fn test_parse_rule_matches_default() {
+        assert_eq!(
+            parse_rule("B3/S23").unwrap(),
+            (DEFAULT_BIRTH_MASK, DEFAULT_SURVIVAL_MASK)
+        );
+    }
+
+    #[test]
+    // HighLife adds a birth on 6 neighbors on top of the standard rule.
+    fn test_parse_rule_highlife() {
+        let (birth_mask, survival_mask) = parse_rule("B36/S23").unwrap();
+
+        assert_eq!(birth_mask, (1 << 3) | (1 << 6));
+        assert_eq!(survival_mask, (1 << 2) | (1 << 3));
+    }
+
+    #[test]
+    // Malformed rule strings should report an error instead of panicking.
+    fn test_parse_rule_rejects_malformed_input() {
+        assert!(parse_rule("B3").is_err());
+        assert!(parse_rule("3/S23").is_err());
+        assert!(parse_rule("B9/S23").is_err());
+    }
+
+    #[test]
+    // Seeds (B2/S) births on exactly 2 neighbors and never survives.
+    fn test_with_rule_changes_births_and_survivors() {
+        let world = World::with_rule(
+            5,
+            5,
+            vec![Position::new(1, 1), Position::new(1, 2)],
+            "B2/S",
+        )
+        .unwrap();
+
+        assert!(world.survivors().is_empty());
+        assert!(world.births().contains(&Position::new(2, 2)));
+    }
+
+    #[test]
+    // A dense world should agree with an equivalent sparse one on
+    // `alive_positions` and on the cells a tick produces.
+    fn test_dense_matches_sparse_next_generation() {
+        let alive = vec![Position::new(1, 1), Position::new(1, 2), Position::new(2, 1)];
+
+        let sparse = World::new(5, 5, alive.clone());
+        let dense = World::dense(5, 5, alive);
+
+        let mut dense_alive = dense.alive_positions();
+        dense_alive.sort_by_key(|pos| (pos.x, pos.y));
+        let mut sparse_alive = sparse.alive_positions();
+        sparse_alive.sort_by_key(|pos| (pos.x, pos.y));
+        assert_eq!(dense_alive, sparse_alive);
+
+        let mut dense_next = dense.next_generation().alive_positions();
+        dense_next.sort_by_key(|pos| (pos.x, pos.y));
+        let mut sparse_next = sparse.next_generation().alive_positions();
+        sparse_next.sort_by_key(|pos| (pos.x, pos.y));
+        assert_eq!(dense_next, sparse_next);
+    }
+
+    #[test]
+    // `live_neighbors` on a dense grid should wrap toroidally just like
+    // the sparse neighbor-list path.
+    fn test_dense_live_neighbors_wraps() {
+        let world = World::dense(
+            5,
+            5,
+            vec![Position::new(1, 1), Position::new(1, 2), Position::new(2, 1)],
+        );
+
+        assert_eq!(world.live_neighbors(Position::new(0, 0)), 1);
+        assert_eq!(world.live_neighbors(Position::new(2, 2)), 3);
+    }
+
+    #[test]
+    // `set_cell` should add or remove cells, wrapping out-of-range
+    // positions, for both storage backends.
+    fn test_set_cell() {
+        let mut sparse = World::new(5, 5, vec![]);
+        sparse.set_cell(Position::new(6, 1), true);
+        assert!(sparse.is_alive(Position::new(1, 1)));
+        sparse.set_cell(Position::new(1, 1), false);
+        assert!(sparse.is_empty(Position::new(1, 1)));
+
+        let mut dense = World::dense(5, 5, vec![]);
+        dense.set_cell(Position::new(2, 2), true);
+        assert!(dense.is_alive(Position::new(2, 2)));
+        dense.set_cell(Position::new(2, 2), false);
+        assert!(dense.is_empty(Position::new(2, 2)));
+    }
+
+    #[test]
+    // `toggle` should flip a cell's alive state each time it's called.
+    fn test_toggle() {
+        let mut world = World::new(5, 5, vec![]);
+
+        world.toggle(Position::new(1, 1));
+        assert!(world.is_alive(Position::new(1, 1)));
+
+        world.toggle(Position::new(1, 1));
+        assert!(world.is_empty(Position::new(1, 1)));
+    }
+
+    #[test]
+    // `clear` should empty a world regardless of storage backend.
+    fn test_clear() {
+        let mut sparse = World::new(5, 5, vec![Position::new(1, 1), Position::new(2, 2)]);
+        sparse.clear();
+        assert!(sparse.alive_positions().is_empty());
+
+        let mut dense = World::dense(5, 5, vec![Position::new(1, 1), Position::new(2, 2)]);
+        dense.clear();
+        assert!(dense.alive_positions().is_empty());
+    }
+
+    #[test]
+    // `randomize` should respect density extremes and be deterministic for
+    // a given seed.
+    fn test_randomize_respects_density_bounds() {
+        let mut empty = World::new(5, 5, vec![Position::new(1, 1)]);
+        empty.randomize(0.0, 42);
+        assert!(empty.alive_positions().is_empty());
+
+        let mut full = World::new(5, 5, vec![]);
+        full.randomize(1.0, 42);
+        assert_eq!(full.alive_positions().len(), 25);
+
+        let mut first = World::new(8, 8, vec![]);
+        first.randomize(0.5, 7);
+        let mut second = World::new(8, 8, vec![]);
+        second.randomize(0.5, 7);
+        assert_eq!(first.alive_positions(), second.alive_positions());
+    }
+
+    #[test]
+    // A glider's RLE pattern should parse into its live cells, anchored
+    // with the pattern's top-left corner at (1, 1).
+    fn test_from_rle_parses_glider() {
+        let rle = "x = 3, y = 3, rule = B3/S23\nbob$2bo$3o!";
+
+        let world = World::from_rle(10, 10, rle).unwrap();
+        let mut alive = world.alive_positions();
+        alive.sort_by_key(|pos| (pos.x, pos.y));
+
+        let mut expected = vec![
+            Position::new(2, 1),
+            Position::new(3, 2),
+            Position::new(1, 3),
+            Position::new(2, 3),
+            Position::new(3, 3),
+        ];
+        expected.sort_by_key(|pos| (pos.x, pos.y));
+
+        assert_eq!(alive, expected);
+    }
+
+    #[test]
+    // Malformed RLE data should report an error instead of panicking.
+    fn test_from_rle_rejects_malformed_input() {
+        assert!(World::from_rle(5, 5, "x = 1, y = 1\nz!").is_err());
+    }
+
+    #[test]
+    // Round-tripping through `to_rle`/`from_rle` should preserve the
+    // pattern's live cells (up to the translation introduced by trimming
+    // to its bounding box).
+    fn test_rle_round_trip() {
+        let glider = glider_pattern(10, 10);
+
+        let rle = glider.to_rle();
+        let parsed = World::from_rle(10, 10, &rle).unwrap();
+
+        // Trimming to the bounding box re-anchors the pattern at (1, 1), so
+        // compare shapes normalized to their own top-left corner.
+        let normalize = |positions: Vec<Position>| -> Vec<(i64, i64)> {
+            let min_x = positions.iter().map(|pos| pos.x).min().unwrap();
+            let min_y = positions.iter().map(|pos| pos.y).min().unwrap();
+            let mut shape: Vec<(i64, i64)> = positions
+                .into_iter()
+                .map(|pos| (pos.x - min_x, pos.y - min_y))
+                .collect();
+            shape.sort();
+            shape
+        };
+
+        assert_eq!(normalize(glider.alive_positions()), normalize(parsed.alive_positions()));
+    }
+
+    #[test]
+    // An empty world should export a minimal, still-parseable RLE string.
+    fn test_to_rle_empty_world() {
+        let world = World::new(5, 5, vec![]);
+
+        assert_eq!(world.to_rle(), "x = 0, y = 0\n!\n");
+    }
+
+    #[test]
+    // `render` should use the default glyphs and lay cells out row by row.
+    fn test_render_uses_default_glyphs() {
+        let world = World::new(3, 2, vec![Position::new(2, 1)]);
+
+        assert_eq!(world.render(), "◻◼◻\n◻◻◻");
+    }
+
+    #[test]
+    // `render_with` should use the caller-supplied glyphs.
+    fn test_render_with_custom_glyphs() {
+        let world = World::new(3, 2, vec![Position::new(2, 1)]);
+
+        assert_eq!(world.render_with('X', '.'), ".X.\n...");
+    }
+
+    #[test]
+    // A 2x2 block is a still life: it should stabilize after a single
+    // step with period 1.
+    fn test_run_until_stable_still_life() {
+        let mut world = World::new(
+            5,
+            5,
+            vec![
+                Position::new(2, 2),
+                Position::new(3, 2),
+                Position::new(2, 3),
+                Position::new(3, 3),
+            ],
+        );
+
+        let result = world.run_until_stable(10);
+        assert_eq!(result, StabilizationResult { steps: 1, period: 1 });
+    }
+
+    #[test]
+    // A blinker is a period-2 oscillator.
+    fn test_run_until_stable_oscillator() {
+        let mut world = World::new(
+            5,
+            5,
+            vec![Position::new(2, 3), Position::new(3, 3), Position::new(4, 3)],
+        );
+
+        let result = world.run_until_stable(10);
+        assert_eq!(result.period, 2);
+    }
+
+    #[test]
+    // A glider drifting across a large world shouldn't repeat within a
+    // handful of steps, so `max_steps` should be exhausted with period 0.
+    fn test_run_until_stable_no_cycle_found() {
+        let mut world = glider_pattern(50, 50);
+
+        let result = world.run_until_stable(3);
+        assert_eq!(result, StabilizationResult { steps: 3, period: 0 });
+    }
 }